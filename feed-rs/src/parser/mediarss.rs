@@ -10,17 +10,40 @@ use regex::{Regex, Captures};
 use std::ops::Add;
 
 // TODO find an RSS feed with media tags in it
-// TODO When an element appears at a shallow level, such as <channel> or <item>, it means that the element should be applied to every media object within its scope.
-// TODO Duplicated elements appearing at deeper levels of the document tree have higher priority over other levels. For example, <media:content> level elements are favored over <item> level elements. The priority level is listed from strongest to weakest: <media:content>, <media:group>, <item>, <channel>.
+
+/// A recoverable problem found while parsing a MediaRSS element in lenient mode
+/// The offending value is dropped (an unknown text type is read as text/plain instead) and parsing continues with the rest of the feed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseWarning {
+    pub message: String,
+}
+
+impl ParseWarning {
+    fn new(message: impl Into<String>) -> Self {
+        ParseWarning { message: message.into() }
+    }
+}
+
+/// Carries the lenient-mode flag and the warnings collected so far through the mediarss handlers
+pub(crate) struct MediaRssContext<'w> {
+    pub(crate) lenient: bool,
+    pub(crate) warnings: &'w mut Vec<ParseWarning>,
+}
+
+impl<'w> MediaRssContext<'w> {
+    fn warn(&mut self, message: impl Into<String>) {
+        self.warnings.push(ParseWarning::new(message));
+    }
+}
 
 /// Handles the top-level "media:group", a collection of mediarss elements.
-pub(crate) fn handle_media_group<R: BufRead>(element: Element<R>) -> ParseFeedResult<Option<MediaObject>> {
+pub(crate) fn handle_media_group<R: BufRead>(element: Element<R>, ctx: &mut MediaRssContext) -> ParseFeedResult<Option<MediaObject>> {
     let mut media_obj = MediaObject::new();
 
     for child in element.children() {
         let child = child?;
         if let Some(NS::MediaRSS) = child.ns_and_tag().0 {
-            handle_media_element(child, &mut media_obj)?;
+            handle_media_element(child, &mut media_obj, ctx)?;
         }
     }
 
@@ -30,21 +53,23 @@ pub(crate) fn handle_media_group<R: BufRead>(element: Element<R>) -> ParseFeedRe
 /// Process the mediarss element into the supplied media object
 /// This isn't the typical pattern, but MediaRSS has a strange shape (content within group, with other elements as peers...or no group and some elements as children)
 /// So this signature is used to parse into a media object from a group, or a default one created at the entry level
-pub(crate) fn handle_media_element<R: BufRead>(element: Element<R>, media_obj: &mut MediaObject) -> ParseFeedResult<()> {
+pub(crate) fn handle_media_element<R: BufRead>(element: Element<R>, media_obj: &mut MediaObject, ctx: &mut MediaRssContext) -> ParseFeedResult<()> {
     match element.ns_and_tag() {
-        (Some(NS::MediaRSS), "title") => media_obj.title = handle_text(element)?,
+        (Some(NS::MediaRSS), "title") => media_obj.title = handle_text(element, ctx)?,
 
-        (Some(NS::MediaRSS), "content") => handle_media_content(element, media_obj)?,
+        (Some(NS::MediaRSS), "content") => handle_media_content(element, media_obj, ctx)?,
 
-        (Some(NS::MediaRSS), "thumbnail") => if_some_then(handle_media_thumbnail(element)?, |thumbnail| media_obj.thumbnails.push(thumbnail)),
+        (Some(NS::MediaRSS), "thumbnail") => if_some_then(handle_media_thumbnail(element, ctx)?, |thumbnail| media_obj.thumbnails.push(thumbnail)),
 
-        (Some(NS::MediaRSS), "description") => media_obj.description = handle_text(element)?,
+        (Some(NS::MediaRSS), "description") => media_obj.description = handle_text(element, ctx)?,
 
         (Some(NS::MediaRSS), "community") => media_obj.community = handle_media_community(element)?,
 
         (Some(NS::MediaRSS), "credit") => if_some_then(handle_media_credit(element)?, |credit| media_obj.credits.push(credit)),
 
-        (Some(NS::MediaRSS), "text") => if_some_then(handle_media_text(element)?, |text| media_obj.texts.push(text)),
+        (Some(NS::MediaRSS), "text") => if_some_then(handle_media_text(element, ctx)?, |text| media_obj.texts.push(text)),
+
+        (Some(NS::MediaRSS), "scenes") => media_obj.scenes.extend(handle_media_scenes(element, ctx)?),
 
         // Nothing required for unknown elements
         _ => {}
@@ -53,6 +78,57 @@ pub(crate) fn handle_media_element<R: BufRead>(element: Element<R>, media_obj: &
     Ok(())
 }
 
+/// Merges the MediaRSS elements declared at "channel", "item", "media:group" and "media:content" scope into a single MediaObject per media object in the feed.
+/// An element declared at a shallow level (e.g. "channel") applies to every media object within its scope; deeper levels take priority over shallower ones when
+/// the same field is set at multiple levels. `scopes` must be ordered from the deepest (highest-priority) to the shallowest (lowest-priority), i.e.
+/// `[content_or_group, item, channel]`. Collection fields (thumbnails, credits, texts, scenes) accumulate across scopes, but a shallower entry that shares
+/// identity with one already present from a deeper scope (a thumbnail's image URI, a credit's entity, a text/scene's time range) is considered an override
+/// of that same item and dropped, rather than kept alongside it as a near-duplicate.
+pub(crate) fn merge_media_scopes(scopes: &[MediaObject]) -> MediaObject {
+    let mut result = MediaObject::new();
+    for scope in scopes {
+        merge_media_object_from(&mut result, scope);
+    }
+    result
+}
+
+// Fills the unset fields of `into` from `from`, leaving any field already set on `into` untouched (it came from a deeper, higher-priority scope)
+fn merge_media_object_from(into: &mut MediaObject, from: &MediaObject) {
+    if into.title.is_none() {
+        into.title = from.title.clone();
+    }
+    if into.description.is_none() {
+        into.description = from.description.clone();
+    }
+    if into.community.is_none() {
+        into.community = from.community.clone();
+    }
+    if into.content.is_none() {
+        into.content = from.content.clone();
+    }
+
+    for thumbnail in &from.thumbnails {
+        if !into.thumbnails.iter().any(|t| t.image.uri == thumbnail.image.uri) {
+            into.thumbnails.push(thumbnail.clone());
+        }
+    }
+    for credit in &from.credits {
+        if !into.credits.iter().any(|c| c.entity == credit.entity) {
+            into.credits.push(credit.clone());
+        }
+    }
+    for text in &from.texts {
+        if !into.texts.iter().any(|t| t.start_time == text.start_time && t.end_time == text.end_time) {
+            into.texts.push(text.clone());
+        }
+    }
+    for scene in &from.scenes {
+        if !into.scenes.iter().any(|s| s.start_time == scene.start_time && s.end_time == scene.end_time) {
+            into.scenes.push(scene.clone());
+        }
+    }
+}
+
 // Handle "media:community"
 fn handle_media_community<R: BufRead>(element: Element<R>) -> ParseFeedResult<Option<MediaCommunity>> {
     let mut community = MediaCommunity::new();
@@ -94,7 +170,7 @@ fn handle_media_community<R: BufRead>(element: Element<R>) -> ParseFeedResult<Op
 }
 
 // Handle the core attributes from "media:content"
-fn handle_media_content<R: BufRead>(element: Element<R>, media_obj: &mut MediaObject) -> ParseFeedResult<()> {
+fn handle_media_content<R: BufRead>(element: Element<R>, media_obj: &mut MediaObject, ctx: &mut MediaRssContext) -> ParseFeedResult<()> {
     let mut content = MediaContent::new();
 
     for attr in &element.attributes {
@@ -106,11 +182,32 @@ fn handle_media_content<R: BufRead>(element: Element<R>, media_obj: &mut MediaOb
             "width" => if_ok_then_some(attr.value.parse::<u32>(), |v| content.width = v),
             "height" => if_ok_then_some(attr.value.parse::<u32>(), |v| content.height = v),
 
+            "fileSize" => content.size = parse_numeric_attr(ctx, "fileSize", &attr.value),
+
+            // Bitrate, framerate and samplingrate are frequently emitted as floating point strings (e.g. "128.5"), so parse as floats rather than integers
+            "bitrate" => content.bitrate = parse_numeric_attr(ctx, "bitrate", &attr.value),
+            "framerate" => content.framerate = parse_numeric_attr(ctx, "framerate", &attr.value),
+            "samplingrate" => content.sampling_rate = parse_numeric_attr(ctx, "samplingrate", &attr.value),
+            "channels" => content.channels = parse_numeric_attr(ctx, "channels", &attr.value),
+
+            // Duration is specified in whole or fractional seconds
+            "duration" => content.duration = parse_numeric_attr::<f64>(ctx, "duration", &attr.value).map(secs_to_duration),
+
+            "medium" => content.medium = MediaMedium::from_attr(&attr.value),
+            "expression" => content.expression = MediaExpression::from_str(&attr.value),
+
+            "isDefault" => if_ok_then_some(attr.value.parse::<bool>(), |v| content.is_default = v),
+
+            "lang" => content.lang = Some(attr.value.clone()),
+
             // Nothing required for unknown elements
             _ => {}
         }
     }
 
+    // Classify adaptive-streaming manifests (DASH/HLS/Smooth Streaming) so consumers know this enclosure needs a player rather than a plain file download
+    content.streaming = StreamingFormat::detect(content.content_type.as_ref(), content.url.as_deref());
+
     // If we found a URL, we consider this a valid element
     // Note ... may have to handle media:player too
     if content.url.is_some() {
@@ -118,7 +215,7 @@ fn handle_media_content<R: BufRead>(element: Element<R>, media_obj: &mut MediaOb
         for child in element.children() {
             let child = child?;
             if let Some(NS::MediaRSS) = child.ns_and_tag().0 {
-                handle_media_element(child, media_obj)?;
+                handle_media_element(child, media_obj, ctx)?;
             }
         }
 
@@ -129,22 +226,35 @@ fn handle_media_content<R: BufRead>(element: Element<R>, media_obj: &mut MediaOb
     Ok(())
 }
 
+// Parses a numeric attribute, recording a warning in lenient mode if it can't be parsed (strict mode silently drops it, as before)
+fn parse_numeric_attr<T: std::str::FromStr>(ctx: &mut MediaRssContext, attr_name: &str, value: &str) -> Option<T> {
+    match value.parse::<T>() {
+        Ok(v) => Some(v),
+        Err(_) => {
+            if ctx.lenient {
+                ctx.warn(format!("could not parse \"{}\" attribute value \"{}\"", attr_name, value));
+            }
+            None
+        }
+    }
+}
+
 // Handles the "media:credit" element
 fn handle_media_credit<R: BufRead>(element: Element<R>) -> ParseFeedResult<Option<MediaCredit>> {
     Ok(element.child_as_text()?
-        .map(|t| MediaCredit::new(t)))
+        .map(MediaCredit::new))
 }
 
 // Handles the "media:text" element
-fn handle_media_text<R: BufRead>(element: Element<R>) -> ParseFeedResult<Option<MediaText>> {
+fn handle_media_text<R: BufRead>(element: Element<R>, ctx: &mut MediaRssContext) -> ParseFeedResult<Option<MediaText>> {
     let media_text = {
         let mut start_time = None;
         let mut end_time = None;
         let mut mime = None;
         for attr in &element.attributes {
             match attr.name.as_str() {
-                "start" => if_some_then(parse_npt(&attr.value), |npt| start_time = Some(npt)),
-                "end" => if_some_then(parse_npt(&attr.value), |npt| end_time = Some(npt)),
+                "start" => start_time = parse_npt_attr(ctx, &attr.value),
+                "end" => end_time = parse_npt_attr(ctx, &attr.value),
                 "type" => mime = match attr.value.as_str() {
                     "plain" => Some(mime::TEXT_PLAIN),
                     "html" => Some(mime::TEXT_HTML),
@@ -174,8 +284,62 @@ fn handle_media_text<R: BufRead>(element: Element<R>) -> ParseFeedResult<Option<
     Ok(media_text)
 }
 
+/// A chapter/segment marker within a media object, taken from a "media:scene" element
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MediaScene {
+    pub title: Option<Text>,
+    pub description: Option<Text>,
+    pub start_time: Option<Duration>,
+    pub end_time: Option<Duration>,
+}
+
+impl MediaScene {
+    fn new() -> Self {
+        MediaScene::default()
+    }
+}
+
+// Handles the "media:scenes" element, a container of "media:scene" chapter markers
+fn handle_media_scenes<R: BufRead>(element: Element<R>, ctx: &mut MediaRssContext) -> ParseFeedResult<Vec<MediaScene>> {
+    let mut scenes = Vec::new();
+
+    for child in element.children() {
+        let child = child?;
+        if let (Some(NS::MediaRSS), "scene") = child.ns_and_tag() {
+            if_some_then(handle_media_scene(child, ctx)?, |scene| scenes.push(scene));
+        }
+    }
+
+    Ok(scenes)
+}
+
+// Handles a single "media:scene" element
+fn handle_media_scene<R: BufRead>(element: Element<R>, ctx: &mut MediaRssContext) -> ParseFeedResult<Option<MediaScene>> {
+    let mut scene = MediaScene::new();
+
+    for child in element.children() {
+        let child = child?;
+        match child.ns_and_tag() {
+            (Some(NS::MediaRSS), "sceneTitle") => scene.title = child.child_as_text()?.map(Text::new),
+            (Some(NS::MediaRSS), "sceneDescription") => scene.description = child.child_as_text()?.map(Text::new),
+            (Some(NS::MediaRSS), "sceneStartTime") => scene.start_time = child.child_as_text()?.and_then(|t| parse_npt_attr(ctx, &t)),
+            (Some(NS::MediaRSS), "sceneEndTime") => scene.end_time = child.child_as_text()?.and_then(|t| parse_npt_attr(ctx, &t)),
+
+            // Nothing required for unknown elements
+            _ => {}
+        }
+    }
+
+    // Drop scenes that carry no title/description/start/end, e.g. an empty "media:scene" or one with only unrecognized children
+    if scene.title.is_none() && scene.description.is_none() && scene.start_time.is_none() && scene.end_time.is_none() {
+        Ok(None)
+    } else {
+        Ok(Some(scene))
+    }
+}
+
 // Handles the "media:thumbnail" element
-fn handle_media_thumbnail<R: BufRead>(element: Element<R>) -> ParseFeedResult<Option<MediaThumbnail>> {
+fn handle_media_thumbnail<R: BufRead>(element: Element<R>, ctx: &mut MediaRssContext) -> ParseFeedResult<Option<MediaThumbnail>> {
     // Extract the attributes on the thumbnail element
     let mut url = None;
     let mut width = None;
@@ -188,7 +352,7 @@ fn handle_media_thumbnail<R: BufRead>(element: Element<R>) -> ParseFeedResult<Op
             "width" => if_ok_then_some(attr.value.parse::<u32>(), |v| width = v),
             "height" => if_ok_then_some(attr.value.parse::<u32>(), |v| height = v),
 
-            "time" => if_some_then(parse_npt(&attr.value), |npt| time = Some(npt)),
+            "time" => time = parse_npt_attr(ctx, &attr.value),
 
             // Nothing required for unknown attributes
             _ => {}
@@ -210,58 +374,235 @@ fn handle_media_thumbnail<R: BufRead>(element: Element<R>) -> ParseFeedResult<Op
     }
 }
 
+// Converts a (possibly fractional) number of seconds into a Duration, rounding down to the nearest millisecond
+fn secs_to_duration(secs: f64) -> Duration {
+    Duration::from_millis((secs * 1000.0) as u64)
+}
+
+/// The type of media represented by a "media:content" element
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MediaMedium {
+    Image,
+    Audio,
+    Video,
+    Document,
+    Executable,
+}
+
+impl MediaMedium {
+    // Named `from_attr` rather than `from_str` so it doesn't shadow `std::str::FromStr` (which returns `Result`, not `Option`)
+    fn from_attr(text: &str) -> Option<Self> {
+        match text {
+            "image" => Some(MediaMedium::Image),
+            "audio" => Some(MediaMedium::Audio),
+            "video" => Some(MediaMedium::Video),
+            "document" => Some(MediaMedium::Document),
+            "executable" => Some(MediaMedium::Executable),
+            _ => None,
+        }
+    }
+}
+
+/// An adaptive-streaming manifest format referenced by a "media:content" element, rather than a single playable file
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StreamingFormat {
+    /// MPEG-DASH manifest ("application/dash+xml", ".mpd")
+    Dash,
+    /// HLS master playlist, listing the available variant streams ("application/vnd.apple.mpegurl" / "application/x-mpegurl", ".m3u8")
+    HlsMaster,
+    /// HLS media playlist, listing the segments of a single variant stream (".m3u")
+    HlsMedia,
+    /// Microsoft Smooth Streaming manifest (".ism"/".ismc")
+    SmoothStreaming,
+}
+
+impl StreamingFormat {
+    // Classifies a media:content enclosure as an adaptive-streaming manifest by MIME type or, failing that, URL extension.
+    // We don't parse the manifest itself, just flag that one is being referenced so a consumer can dispatch to the appropriate player.
+    // MIME subtypes are matched case-insensitively: the "mime" crate preserves source casing in `as_str()`, and the canonical
+    // Apple HLS type is commonly emitted as "application/x-mpegURL". The MIME type doesn't distinguish master from media
+    // playlists, so a MIME match is treated as a master playlist (the usual case for a feed enclosure); ".m3u" is the only
+    // signal we have for a bare media playlist.
+    fn detect(content_type: Option<&Mime>, url: Option<&str>) -> Option<Self> {
+        if let Some(content_type) = content_type {
+            if content_type.type_() == mime::APPLICATION {
+                let subtype = content_type.subtype().as_str();
+                // The "mime" crate splits a structured syntax suffix (e.g. "+xml") out of the subtype, so "dash+xml" is seen as subtype "dash" with suffix "xml"
+                if subtype.eq_ignore_ascii_case("dash") && content_type.suffix().is_some_and(|s| s.as_str().eq_ignore_ascii_case("xml")) {
+                    return Some(StreamingFormat::Dash);
+                }
+                if subtype.eq_ignore_ascii_case("vnd.apple.mpegurl") || subtype.eq_ignore_ascii_case("x-mpegurl") {
+                    return Some(StreamingFormat::HlsMaster);
+                }
+            }
+        }
+
+        let path = url?.split(['?', '#']).next().unwrap_or("");
+        if path.ends_with(".mpd") {
+            Some(StreamingFormat::Dash)
+        } else if path.ends_with(".m3u8") {
+            Some(StreamingFormat::HlsMaster)
+        } else if path.ends_with(".m3u") {
+            Some(StreamingFormat::HlsMedia)
+        } else if path.ends_with(".ism") || path.ends_with(".ismc") || path.ends_with(".ismv") {
+            Some(StreamingFormat::SmoothStreaming)
+        } else {
+            None
+        }
+    }
+}
+
+/// Whether a "media:content" element is the full version of the content, or a sample/trailer
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MediaExpression {
+    Sample,
+    Full,
+    Nonstop,
+}
+
+impl MediaExpression {
+    fn from_str(text: &str) -> Option<Self> {
+        match text {
+            "sample" => Some(MediaExpression::Sample),
+            "full" => Some(MediaExpression::Full),
+            "nonstop" => Some(MediaExpression::Nonstop),
+            _ => None,
+        }
+    }
+}
+
+// Parses a single-instant NPT attribute (media:text start/end, media:thumbnail time, media:scene start/end), recording a
+// warning in lenient mode if it's malformed, the "now" marker (which has no fixed Duration until evaluated at play time),
+// or a "start-end" range (none of these attributes are specified as a range, only a single instant)
+fn parse_npt_attr(ctx: &mut MediaRssContext, value: &str) -> Option<Duration> {
+    match parse_npt(value) {
+        NptRange { start: Some(Npt::Duration(duration)), end: None } => Some(duration),
+        _ => {
+            if ctx.lenient {
+                ctx.warn(format!("could not parse NPT value \"{}\"", value));
+            }
+            None
+        }
+    }
+}
+
 // Handles a title or description element
-fn handle_text<R: BufRead>(element: Element<R>) -> ParseFeedResult<Option<Text>> {
+fn handle_text<R: BufRead>(element: Element<R>, ctx: &mut MediaRssContext) -> ParseFeedResult<Option<Text>> {
     // Find type, defaulting to "plain" if not present
     let type_attr = element.attributes.iter().find(|a| &a.name == "type").map_or("plain", |a| a.value.as_str());
 
     let mime = match type_attr {
-        "plain" => Ok(mime::TEXT_PLAIN),
-        "html" => Ok(mime::TEXT_HTML),
+        "plain" => Some(mime::TEXT_PLAIN),
+        "html" => Some(mime::TEXT_HTML),
 
-        // Unknown content type
-        _ => Err(ParseFeedError::ParseError(ParseErrorKind::UnknownMimeType(type_attr.into()))),
-    }?;
+        // Unknown content type: in lenient mode fall back to plain text, otherwise this is a fatal error
+        _ if ctx.lenient => {
+            ctx.warn(format!("unknown text type \"{}\", falling back to text/plain", type_attr));
+            Some(mime::TEXT_PLAIN)
+        }
+        _ => None,
+    };
+
+    let mime = match mime {
+        Some(mime) => mime,
+        None => return Err(ParseFeedError::ParseError(ParseErrorKind::UnknownMimeType(type_attr.into()))),
+    };
+
+    let text = element.children_as_string()?.map(|content| {
+        let mut text = Text::new(content);
+        text.content_type = mime;
+        text
+    });
 
-    element
-        .children_as_string()?
-        .map(|content| {
-            let mut text = Text::new(content);
-            text.content_type = mime;
-            Some(text)
-        })
-        // Need the text for a text element
-        .ok_or(ParseFeedError::ParseError(ParseErrorKind::MissingContent("text")))
+    match text {
+        Some(text) => Ok(Some(text)),
+
+        // Missing text: in lenient mode skip the element, otherwise this is a fatal error
+        None if ctx.lenient => {
+            ctx.warn("missing text content, skipping element".to_string());
+            Ok(None)
+        }
+        None => Err(ParseFeedError::ParseError(ParseErrorKind::MissingContent("text"))),
+    }
 }
 
 
 lazy_static! {
     // Initialise the set of regular expressions we use to parse the NPT format
     // See "3.6 Normal Play Time" in https://www.ietf.org/rfc/rfc2326.txt
+    // Anchored with ^...$ so that trailing/leading junk (e.g. "12:05:35junk") is rejected rather than silently ignored
     static ref NPT_HHMMSS: Regex = {
         // Extract hours (h), minutes (m), seconds (s) and fractional seconds (f)
-        Regex::new(r#"(?P<h>\d+):(?P<m>\d{2}):(?P<s>\d{2})(\.(?P<f>\d+))?"#).unwrap()
+        Regex::new(r#"^(?P<h>\d+):(?P<m>\d{2}):(?P<s>\d{2})(\.(?P<f>\d+))?$"#).unwrap()
     };
     static ref NPT_SEC: Regex = {
         // Extract seconds (s) and fractional seconds (f)
-        Regex::new(r#"(?P<s>\d+)(\.(?P<f>\d+))?"#).unwrap()
+        Regex::new(r#"^(?P<s>\d+)(\.(?P<f>\d+))?$"#).unwrap()
     };
 }
 
-/// Parses "normal play time" per the RSS media spec
+/// A single "normal play time" instant per RFC 2326 3.6: either a concrete duration, or the literal "now" marker
+#[derive(Clone, Debug, PartialEq)]
+pub enum Npt {
+    Now,
+    Duration(Duration),
+}
+
+/// An NPT range, e.g. "10:00-20:00", "10:00-" (open end) or "-20:00" (open start)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NptRange {
+    pub start: Option<Npt>,
+    pub end: Option<Npt>,
+}
+
+/// Parses a "normal play time" range per the RSS media spec and RFC 2326 3.6
 /// NPT has a second or sub-second resolution. It is specified as H:M:S.h (npt-hhmmss) or S.h (npt-sec), where H=hours, M=minutes, S=second and h=fractions of a second.
-fn parse_npt(text: &str) -> Option<Duration> {
+/// It may also be the literal "now", and may be expressed as a "start-end" range where either side can be omitted to leave that bound open
+///
+/// `parse_npt_attr` routes every NPT attribute we parse through here so the "now" marker and an (invalid, but seen in the wild) range form
+/// are recognised rather than silently failing to parse; since those attributes are each specified as a single instant, only a `NptRange`
+/// with a `start` and no `end` yields a usable `Duration` there.
+pub(crate) fn parse_npt(text: &str) -> NptRange {
+    match text.find('-') {
+        Some(index) => NptRange {
+            start: parse_npt_instant(&text[..index]),
+            end: parse_npt_instant(&text[index + 1..]),
+        },
+        None => NptRange { start: parse_npt_instant(text), end: None },
+    }
+}
+
+// Parses a single NPT instant, returning None for an empty or malformed value
+fn parse_npt_instant(text: &str) -> Option<Npt> {
+    let text = text.trim();
+    if text.is_empty() {
+        None
+    } else if text == "now" {
+        Some(Npt::Now)
+    } else {
+        parse_npt_duration(text).map(Npt::Duration)
+    }
+}
+
+/// Parses a single NPT instant as a plain Duration; "now" and range forms are not accepted and yield None
+fn parse_npt_duration(text: &str) -> Option<Duration> {
     // Try npt-hhmmss format first
     if let Some(captures) = NPT_HHMMSS.captures(text) {
         let h = captures.name("h");
         let m = captures.name("m");
         let s = captures.name("s");
-        match (h, m, s) {
-            (Some(h), Some(m), Some(s)) => {
-                // Parse the hours, minutes and seconds
-                let mut seconds = s.as_str().parse::<u64>().unwrap();
-                seconds += m.as_str().parse::<u64>().unwrap() * 60;
-                seconds += h.as_str().parse::<u64>().unwrap() * 3600;
+        if let (Some(h), Some(m), Some(s)) = (h, m, s) {
+            // Parse the hours, minutes and seconds, bailing out (rather than panicking) if the unbounded hours group overflows a u64
+            let seconds = s.as_str().parse::<u64>().ok();
+            let minutes = m.as_str().parse::<u64>().ok().and_then(|m| m.checked_mul(60));
+            let hours = h.as_str().parse::<u64>().ok().and_then(|h| h.checked_mul(3600));
+            let seconds = seconds
+                .zip(minutes)
+                .and_then(|(s, m)| s.checked_add(m))
+                .zip(hours)
+                .and_then(|(sm, h)| sm.checked_add(h));
+
+            if let Some(seconds) = seconds {
                 let mut duration = Duration::from_secs(seconds);
 
                 // Add fractional seconds if present
@@ -269,17 +610,12 @@ fn parse_npt(text: &str) -> Option<Duration> {
 
                 return Some(duration);
             }
-
-            // String is not in npt-hhmmss format
-            _ => {}
         }
     }
 
     // Next try npt-sec
     if let Some(captures) = NPT_SEC.captures(text) {
-        if let Some(s) = captures.name("s") {
-            // Parse the seconds
-            let seconds = s.as_str().parse::<u64>().unwrap();
+        if let Some(seconds) = captures.name("s").and_then(|s| s.as_str().parse::<u64>().ok()) {
             let mut duration = Duration::from_secs(seconds);
 
             // Add fractional seconds if present
@@ -313,8 +649,164 @@ mod tests {
     // Verify we can parse NPT times
     #[test]
     fn test_parse_npt() {
-        assert_eq!(parse_npt("12:05:35").unwrap(), Duration::from_secs(12 * 3600 + 5 * 60 + 35));
-        assert_eq!(parse_npt("12:05:35.123").unwrap(), Duration::from_millis(12 * 3600000 + 5 * 60000 + 35 * 1000 + 123));
-        assert_eq!(parse_npt("123.45").unwrap(), Duration::from_millis(123450));
+        assert_eq!(parse_npt_duration("12:05:35").unwrap(), Duration::from_secs(12 * 3600 + 5 * 60 + 35));
+        assert_eq!(parse_npt_duration("12:05:35.123").unwrap(), Duration::from_millis(12 * 3600000 + 5 * 60000 + 35 * 1000 + 123));
+        assert_eq!(parse_npt_duration("123.45").unwrap(), Duration::from_millis(123450));
+
+        // Unanchored junk should no longer match
+        assert_eq!(parse_npt_duration("12:05:35junk"), None);
+        assert_eq!(parse_npt_duration("1.2.3"), None);
+
+        // An overlong hours/seconds group that overflows u64 must return None rather than panic
+        assert_eq!(parse_npt_duration("99999999999999999999:00:00"), None);
+        assert_eq!(parse_npt_duration("99999999999999999999"), None);
+    }
+
+    // Verify the "now" marker and range syntax from RFC 2326 3.6
+    #[test]
+    fn test_parse_npt_range() {
+        assert_eq!(parse_npt("now"), NptRange { start: Some(Npt::Now), end: None });
+
+        let range = parse_npt("10:00:00-20:00:00");
+        assert_eq!(range.start, Some(Npt::Duration(Duration::from_secs(10 * 3600))));
+        assert_eq!(range.end, Some(Npt::Duration(Duration::from_secs(20 * 3600))));
+
+        let open_end = parse_npt("10:00:00-");
+        assert_eq!(open_end.start, Some(Npt::Duration(Duration::from_secs(10 * 3600))));
+        assert_eq!(open_end.end, None);
+
+        let open_start = parse_npt("-20:00:00");
+        assert_eq!(open_start.start, None);
+        assert_eq!(open_start.end, Some(Npt::Duration(Duration::from_secs(20 * 3600))));
+    }
+
+    // Verify the deepest scope wins for scalar fields, a field only set at a shallower scope is still inherited,
+    // and collection fields accumulate distinct entries from every scope rather than the deepest one replacing the rest
+    #[test]
+    fn test_merge_media_scopes() {
+        let mut deepest = MediaObject::new();
+        deepest.title = Some(Text::new("deepest title".to_string()));
+        deepest.thumbnails.push(MediaThumbnail::new(Image::new("https://example.com/deepest.jpg".to_string())));
+
+        let mut shallowest = MediaObject::new();
+        shallowest.title = Some(Text::new("shallowest title".to_string()));
+        shallowest.description = Some(Text::new("shallowest description".to_string()));
+        shallowest.thumbnails.push(MediaThumbnail::new(Image::new("https://example.com/shallowest.jpg".to_string())));
+
+        // `scopes` is ordered deepest (highest-priority) first, as merge_media_scopes documents
+        let merged = merge_media_scopes(&[deepest, shallowest]);
+
+        assert_eq!(merged.title.unwrap().content, "deepest title");
+        assert_eq!(merged.description.unwrap().content, "shallowest description");
+        assert_eq!(merged.thumbnails.len(), 2);
+    }
+
+    // Verify a shallower collection entry that shares identity with a deeper one (same thumbnail URI, same credit entity,
+    // same text/scene time range) is treated as an override and dropped, rather than kept alongside the deeper entry
+    #[test]
+    fn test_merge_media_scopes_overrides_by_identity() {
+        let mut deepest = MediaObject::new();
+        deepest
+            .thumbnails
+            .push(MediaThumbnail::new(Image::new("https://example.com/thumb.jpg".to_string()).width(100)));
+        deepest.credits.push(MediaCredit::new("Alice".to_string()));
+
+        let mut shallowest = MediaObject::new();
+        // Same URI as the deepest thumbnail, but a different width - this is the same thumbnail overridden, not a second one
+        shallowest
+            .thumbnails
+            .push(MediaThumbnail::new(Image::new("https://example.com/thumb.jpg".to_string()).width(200)));
+        // Same entity as the deepest credit - also an override, not a second credit
+        shallowest.credits.push(MediaCredit::new("Alice".to_string()));
+        // A distinct thumbnail and credit should still be kept
+        shallowest
+            .thumbnails
+            .push(MediaThumbnail::new(Image::new("https://example.com/other.jpg".to_string())));
+        shallowest.credits.push(MediaCredit::new("Bob".to_string()));
+
+        let merged = merge_media_scopes(&[deepest, shallowest]);
+
+        assert_eq!(merged.thumbnails.len(), 2);
+        assert_eq!(merged.thumbnails[0].image.width, Some(100)); // the deeper scope's width wins
+        assert_eq!(merged.credits.len(), 2);
+        assert_eq!(merged.credits[0].entity, "Alice");
+        assert_eq!(merged.credits[1].entity, "Bob");
+    }
+
+    // Verify the numeric and enum attributes of "media:content" are parsed, including floating-point duration/bitrate/framerate/samplingrate
+    #[test]
+    fn test_handle_media_content() {
+        let xml = r#"<media:content xmlns:media="http://search.yahoo.com/mrss/"
+            url="https://example.com/video.mp4" fileSize="12345" bitrate="128.5" framerate="29.97"
+            samplingrate="44.1" channels="2" duration="185.5" medium="video" expression="full"
+            isDefault="true" lang="en"/>"#;
+
+        let source = crate::xml::ElementSource::new(xml.as_bytes());
+        let element = source.root().unwrap().unwrap();
+
+        let mut media_obj = MediaObject::new();
+        let mut warnings = Vec::new();
+        let mut ctx = MediaRssContext { lenient: false, warnings: &mut warnings };
+        handle_media_content(element, &mut media_obj, &mut ctx).unwrap();
+
+        let content = media_obj.content.unwrap();
+        assert_eq!(content.url, Some("https://example.com/video.mp4".to_string()));
+        assert_eq!(content.size, Some(12345));
+        assert_eq!(content.bitrate, Some(128.5));
+        assert_eq!(content.framerate, Some(29.97));
+        assert_eq!(content.sampling_rate, Some(44.1));
+        assert_eq!(content.channels, Some(2));
+        assert_eq!(content.duration, Some(Duration::from_millis(185500)));
+        assert_eq!(content.medium, Some(MediaMedium::Video));
+        assert_eq!(content.expression, Some(MediaExpression::Full));
+        assert_eq!(content.is_default, Some(true));
+        assert_eq!(content.lang, Some("en".to_string()));
+    }
+
+    // Verify "media:scenes" parses each "media:scene" child into a chapter marker, with NPT start/end times
+    #[test]
+    fn test_handle_media_scenes() {
+        let xml = r#"<media:scenes xmlns:media="http://search.yahoo.com/mrss/">
+            <media:scene>
+                <media:sceneTitle>Intro</media:sceneTitle>
+                <media:sceneDescription>The introduction</media:sceneDescription>
+                <media:sceneStartTime>00:00:00</media:sceneStartTime>
+                <media:sceneEndTime>00:01:30</media:sceneEndTime>
+            </media:scene>
+            <media:scene>
+                <media:sceneStartTime>00:01:30</media:sceneStartTime>
+            </media:scene>
+        </media:scenes>"#;
+
+        let source = crate::xml::ElementSource::new(xml.as_bytes());
+        let element = source.root().unwrap().unwrap();
+
+        let mut warnings = Vec::new();
+        let mut ctx = MediaRssContext { lenient: false, warnings: &mut warnings };
+        let scenes = handle_media_scenes(element, &mut ctx).unwrap();
+
+        assert_eq!(scenes.len(), 2);
+        assert_eq!(scenes[0].title.as_ref().unwrap().content, "Intro");
+        assert_eq!(scenes[0].description.as_ref().unwrap().content, "The introduction");
+        assert_eq!(scenes[0].start_time, Some(Duration::from_secs(0)));
+        assert_eq!(scenes[0].end_time, Some(Duration::from_secs(90)));
+        assert!(scenes[1].title.is_none());
+        assert_eq!(scenes[1].start_time, Some(Duration::from_secs(90)));
+    }
+
+    // Verify streaming format detection by MIME type (including case-insensitive HLS subtypes) and, failing that, URL extension
+    #[test]
+    fn test_streaming_format_detect() {
+        let dash_mime = "application/dash+xml".parse().unwrap();
+        assert_eq!(StreamingFormat::detect(Some(&dash_mime), None), Some(StreamingFormat::Dash));
+
+        let hls_mime = "application/x-mpegURL".parse().unwrap();
+        assert_eq!(StreamingFormat::detect(Some(&hls_mime), None), Some(StreamingFormat::HlsMaster));
+
+        assert_eq!(StreamingFormat::detect(None, Some("https://example.com/master.m3u8?token=abc")), Some(StreamingFormat::HlsMaster));
+        assert_eq!(StreamingFormat::detect(None, Some("https://example.com/media.m3u")), Some(StreamingFormat::HlsMedia));
+        assert_eq!(StreamingFormat::detect(None, Some("https://example.com/stream.ism")), Some(StreamingFormat::SmoothStreaming));
+        assert_eq!(StreamingFormat::detect(None, Some("https://example.com/manifest.mpd")), Some(StreamingFormat::Dash));
+        assert_eq!(StreamingFormat::detect(None, Some("https://example.com/video.mp4")), None);
     }
 }
\ No newline at end of file